@@ -0,0 +1,297 @@
+//! Depth-limited expectimax solver for `Grid`.
+//!
+//! `Grid::best_move` alternates MAX nodes (the player picks the best
+//! direction) with CHANCE nodes (the game spawns a `1` with probability 0.9
+//! or a `2` with probability 0.1 on a random empty cell), bottoming out at a
+//! heuristic board evaluation once the search depth runs out.
+
+use crate::{Grid, Direction};
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::time::{Duration, Instant};
+
+const EMPTY_WEIGHT: f64 = 2.7;
+const MONOTONICITY_WEIGHT: f64 = 1.0;
+const SMOOTHNESS_WEIGHT: f64 = 0.1;
+const CORNER_WEIGHT: f64 = 2.0;
+
+/// Wall-clock budget for a single `best_move` call. Bounding by time rather
+/// than by a fixed depth means auto-play stays responsive on a debug build
+/// or a slow machine, not just on whatever hardware the depth constant was
+/// tuned against.
+const SEARCH_BUDGET: Duration = Duration::from_millis(100);
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A minimal FNV-1a hasher - folding a 4x4 board's exponents into a 64-bit
+/// state costs only a few dozen ops, which keeps the transposition table
+/// cheap to hit on every MAX node.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Caches MAX-node values for a single `best_move` call, keyed by board hash
+/// and remaining depth, so boards reached via different move orders are
+/// only evaluated once.
+type FnvHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+fn board_hash(grid: &Grid) -> u64 {
+    let mut hasher = FnvHasher::default();
+    grid.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl Grid {
+    /// Returns the direction the expectimax search rates highest, or `None`
+    /// if no move would change the board.
+    ///
+    /// `max_depth` caps how many MAX/CHANCE layers the search is allowed to
+    /// explore, but the search deepens iteratively and every node checks
+    /// [`SEARCH_BUDGET`] before recursing further, falling back to the leaf
+    /// heuristic once it elapses - so a call stays close to the budget
+    /// regardless of `max_depth` or how fast the machine is, instead of only
+    /// being checked between whole depths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// let _best = grid.best_move(6);
+    /// ```
+    pub fn best_move(&self, max_depth: u8) -> Option<Direction> {
+        let deadline = Instant::now() + SEARCH_BUDGET;
+        let mut best = None;
+
+        for depth in 1..=max_depth {
+            if Instant::now() >= deadline {
+                break;
+            }
+
+            let mut cache = FnvHashMap::default();
+            let result = Direction::ALL.iter()
+                .filter_map(|&dir| {
+                    let (rows, _) = self.tilt(dir);
+                    if rows == self.rows {
+                        return None;
+                    }
+                    let tilted = Grid { rows, ..Default::default() };
+                    Some((dir, chance_node(&tilted, depth, &mut cache, deadline)))
+                })
+                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+                .map(|(dir, _)| dir);
+
+            // No direction changes the board at any depth; stop early
+            // instead of repeating the same empty search.
+            result?;
+            best = result;
+        }
+
+        best
+    }
+}
+
+fn max_node(grid: &Grid, depth: u8, cache: &mut FnvHashMap<(u64, u8), f64>, deadline: Instant) -> f64 {
+    if depth == 0 || Instant::now() >= deadline {
+        return heuristic(grid);
+    }
+
+    let key = (board_hash(grid), depth);
+    if let Some(&value) = cache.get(&key) {
+        return value;
+    }
+
+    let best = Direction::ALL.iter().filter_map(|&dir| {
+        let (rows, _) = grid.tilt(dir);
+        if rows == grid.rows {
+            return None;
+        }
+        let tilted = Grid { rows, ..Default::default() };
+        Some(chance_node(&tilted, depth - 1, cache, deadline))
+    }).fold(None, |best: Option<f64>, value| {
+        Some(best.map_or(value, |best| best.max(value)))
+    });
+
+    let value = best.unwrap_or_else(|| heuristic(grid));
+    cache.insert(key, value);
+    value
+}
+
+fn chance_node(grid: &Grid, depth: u8, cache: &mut FnvHashMap<(u64, u8), f64>, deadline: Instant) -> f64 {
+    if Instant::now() >= deadline {
+        return heuristic(grid);
+    }
+
+    let empty_cells: Vec<(usize, usize)> = grid.rows.iter().enumerate().flat_map(|(x, row)| {
+        row.iter().enumerate().filter(|(_, &cell)| cell == 0).map(move |(y, _)| (x, y))
+    }).collect();
+
+    if empty_cells.is_empty() {
+        return heuristic(grid);
+    }
+
+    let empty_count = empty_cells.len() as f64;
+    empty_cells.iter().map(|&(x, y)| {
+        let mut with_two = grid.rows.clone();
+        with_two[x][y] = 1;
+        let mut with_four = grid.rows.clone();
+        with_four[x][y] = 2;
+
+        let value_two = max_node(&Grid { rows: with_two, ..Default::default() }, depth, cache, deadline);
+        let value_four = max_node(&Grid { rows: with_four, ..Default::default() }, depth, cache, deadline);
+
+        (value_two * 0.9 + value_four * 0.1) / empty_count
+    }).sum()
+}
+
+fn heuristic(grid: &Grid) -> f64 {
+    empty_cells(grid) * EMPTY_WEIGHT
+        + monotonicity(grid) * MONOTONICITY_WEIGHT
+        - smoothness(grid) * SMOOTHNESS_WEIGHT
+        + corner_bonus(grid) * CORNER_WEIGHT
+}
+
+fn empty_cells(grid: &Grid) -> f64 {
+    grid.rows.iter().flatten().filter(|&&cell| cell == 0).count() as f64
+}
+
+/// Sums, per axis, how close the rows/columns are to being sorted in one
+/// direction - keeping the board monotonic makes it easier to funnel big
+/// tiles into a corner.
+fn monotonicity(grid: &Grid) -> f64 {
+    let mut totals = [0f64; 4]; // increasing/decreasing rows, increasing/decreasing cols
+
+    for row in &grid.rows {
+        for i in 0..row.len().saturating_sub(1) {
+            let current = row[i] as f64;
+            let next = row[i + 1] as f64;
+            if current > next {
+                totals[0] += current - next;
+            } else {
+                totals[1] += next - current;
+            }
+        }
+    }
+
+    for col in 0..grid.rows[0].len() {
+        for i in 0..grid.rows.len().saturating_sub(1) {
+            let current = grid.rows[i][col] as f64;
+            let next = grid.rows[i + 1][col] as f64;
+            if current > next {
+                totals[2] += current - next;
+            } else {
+                totals[3] += next - current;
+            }
+        }
+    }
+
+    // Penalise only the steps that go against each axis's dominant order, so a
+    // fully sorted row/column scores 0 and anything less sorted scores lower.
+    -(totals[0].min(totals[1]) + totals[2].min(totals[3]))
+}
+
+/// Sums the absolute exponent difference between every pair of orthogonal
+/// neighbors - a smoother board is easier to merge further.
+fn smoothness(grid: &Grid) -> f64 {
+    let mut total = 0f64;
+
+    for (x, row) in grid.rows.iter().enumerate() {
+        for (y, &cell) in row.iter().enumerate() {
+            if y + 1 < row.len() {
+                total += (cell as f64 - row[y + 1] as f64).abs();
+            }
+            if x + 1 < grid.rows.len() {
+                total += (cell as f64 - grid.rows[x + 1][y] as f64).abs();
+            }
+        }
+    }
+
+    total
+}
+
+/// Rewards boards where the largest tile sits in a corner, since that tile
+/// then never needs to move again.
+fn corner_bonus(grid: &Grid) -> f64 {
+    let largest = grid.rows.iter().flatten().cloned().max().unwrap_or(0);
+    let last_row = grid.rows.len() - 1;
+    let last_col = grid.rows[0].len() - 1;
+    let corners = [(0, 0), (0, last_col), (last_row, 0), (last_row, last_col)];
+
+    if corners.iter().any(|&(x, y)| grid.rows[x][y] == largest) {
+        largest as f64
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_move_returns_none_when_stuck() {
+        let stuck = Grid::from_rows(vec![
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4, 5],
+            vec![3, 4, 5, 6],
+            vec![4, 5, 6, 7],
+        ]);
+        assert_eq!(stuck.best_move(3), None);
+    }
+
+    #[test]
+    fn best_move_picks_a_direction_that_changes_the_board() {
+        let grid = Grid::from_rows(vec![
+            vec![1, 1, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        let dir = grid.best_move(2).expect("a move should be available");
+        assert_ne!(grid.tilt(dir).0, grid.rows);
+    }
+
+    #[test]
+    fn transposition_cache_is_shared_across_equal_boards() {
+        let reached_one_way = Grid::from_rows(vec![
+            vec![1, 1, 2, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        let reached_another_way = Grid::from_rows(vec![
+            vec![1, 1, 2, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+
+        let deadline = Instant::now() + SEARCH_BUDGET;
+        let mut cache = FnvHashMap::default();
+        let first = max_node(&reached_one_way, 2, &mut cache, deadline);
+        let entries_after_first = cache.len();
+        let second = max_node(&reached_another_way, 2, &mut cache, deadline);
+
+        assert_eq!(first, second);
+        assert_eq!(cache.len(), entries_after_first, "an identical board should hit the cache, not insert a new entry");
+    }
+}
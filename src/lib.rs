@@ -1,19 +1,70 @@
 use rand::Rng;
 use phf::phf_map;
 use std::fmt;
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Index, IndexMut};
+use std::str::FromStr;
+
+mod ai;
+
+/// A zero-based row coordinate into a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Row(pub usize);
+
+/// A zero-based column coordinate into a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Col(pub usize);
+
+impl Add<usize> for Row {
+    type Output = Row;
+    fn add(self, rhs: usize) -> Row {
+        Row(self.0 + rhs)
+    }
+}
+
+impl Add<usize> for Col {
+    type Output = Col;
+    fn add(self, rhs: usize) -> Col {
+        Col(self.0 + rhs)
+    }
+}
 
 /// Holds the game state.
 pub struct Grid {
     //rows contain cols
-    rows: Vec<Vec<u8>>,
+    pub(crate) rows: Vec<Vec<u8>>,
     pipes: &'static PipeMap,
+    score: u64,
+}
+
+impl Hash for Grid {
+    /// Hashes the grid by its tiles only, ignoring the cosmetic `pipes`
+    /// border and the `score`. Equality/hash are board-state only by
+    /// design, so the `ai` solver's transposition table can reuse a cached
+    /// value for any grid with the same tiles regardless of how it scored
+    /// getting there.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rows.hash(state);
+    }
 }
 
+impl PartialEq for Grid {
+    /// Compares grids by their tiles only, ignoring the cosmetic `pipes`
+    /// border and the `score` - see the note on the `Hash` impl.
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+
+impl Eq for Grid {}
+
 impl Default for Grid {
     fn default() -> Self {
         Grid {
             rows: vec![vec![0; 4]; 4],
             pipes: &PIPEMAP_THICK,
+            score: 0,
         }
     }
 }
@@ -83,8 +134,47 @@ impl Grid {
         Grid {
             rows: self.rows.clone(),
             pipes,
+            score: self.score,
         }
     }
+
+    /// Returns the running score: the sum of every merged tile's value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert_eq!(grid.score(), 0);
+    /// ```
+    pub fn score(&self) -> u64 {
+        self.score
+    }
+
+    /// Serializes the grid to the text format read by `FromStr`: one row per
+    /// line, tile values separated by spaces, `.` for an empty cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 2]]);
+    /// assert_eq!(grid.to_save_string(), "2 .\n. 4");
+    /// ```
+    pub fn to_save_string(&self) -> String {
+        self.rows.iter().map(|row| {
+            row.iter().map(|&power| {
+                if power == 0 {
+                    ".".to_string()
+                } else {
+                    (2u64.pow(power as u32)).to_string()
+                }
+            }).collect::<Vec<String>>().join(" ")
+        }).collect::<Vec<String>>().join("\n")
+    }
+
     /// Gets the size of the grid in characters and with borders.
     ///
     /// # Examples
@@ -122,89 +212,97 @@ impl Grid {
     /// 
     /// ```
     pub fn slide(&self, dir: Direction) -> Result<Grid, &'static str> {
-        let mut rows: Vec<Vec<u8>> = self.rows.clone();
-
-        (|| {
-            match dir {
-                Direction::LEFT => {
-                    //Rotate
-                    // -
-                    //Operate
-                    rows = rows.iter().map(|row| self.combine_row(row)).collect();
-                    //Rotate back
-                    // -
-                    //Return
-                    return Ok(());
-                }
-                
-                Direction::RIGHT => {
-                    //Rotate
-                    rows = rows.iter().map(|row| row.iter().rev().cloned().collect()).collect();
-                    //Operate
-                    rows = rows.iter().map(|row| self.combine_row(row)).collect();
-                    //Rotate back
-                    rows = rows.iter().map(|row| row.iter().rev().cloned().collect()).collect();
-                    //Return
-                    return Ok(());
-                }
-                
-                Direction::UP => {
-                    //Rotate
-                    rows = (0..rows[0].len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect();
-                    //Operate
-                    rows = rows.iter().map(|row| self.combine_row(row)).collect();
-                    //Rotate back
-                    rows = (0..rows[0].len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect();
-                    //Return
-                    return Ok(());
-                }
-                Direction::DOWN => {
-                    //Rotate
-                    rows = (0..rows[0].len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect();
-                    rows = rows.iter().map(|row| row.iter().rev().cloned().collect()).collect();
-                    //Operate
-                    rows = rows.iter().map(|row| self.combine_row(row)).collect();
-                    //Rotate back
-                    rows = rows.iter().map(|row| row.iter().rev().cloned().collect()).collect();
-                    rows = (0..rows[0].len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect();
-                    //Return
-                    return Ok(());
-                }
-            }
-        })()?;
+        let (rows, gained) = self.tilt(dir);
 
-        let new_grid = Grid { rows, ..Default::default() }; 
-        let new_grid_with_new_number = new_grid.add_random_number()?;
-        //see if grid has changed
-        if new_grid.rows != self.rows {
-            return Ok(new_grid_with_new_number);
+        //the move did nothing - only a real game over if no direction could do anything either
+        if rows == self.rows {
+            return Err(if self.can_move() { "cannot move in that direction" } else { "no more options" });
+        }
+
+        let new_grid = Grid { rows, score: self.score + gained, ..Default::default() };
+        new_grid.add_random_number()
+    }
+
+    /// Returns true if any direction would change the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert!(grid.can_move());
+    /// ```
+    pub fn can_move(&self) -> bool {
+        Direction::ALL.iter().any(|&dir| self.tilt(dir).0 != self.rows)
+    }
+
+    /// Slides and combines the grid in the given direction, without adding a
+    /// new tile, returning the new rows and the score gained from any
+    /// merges. Used internally by `slide`, `can_move` and the AI search in
+    /// `ai`.
+    ///
+    /// Every direction is expressed as a combination of `transpose` and
+    /// `reverse_rows` around a left-combine: LEFT is the combine itself,
+    /// RIGHT mirrors it, UP transposes rows into columns first, and DOWN
+    /// does both.
+    pub(crate) fn tilt(&self, dir: Direction) -> (Vec<Vec<u8>>, u64) {
+        let combine = |rows: &Vec<Vec<u8>>| -> (Vec<Vec<u8>>, u64) {
+            let mut gained = 0u64;
+            let rows = rows.iter().map(|row| {
+                let (row, row_gained) = self.combine_row(row);
+                gained += row_gained;
+                row
+            }).collect();
+            (rows, gained)
+        };
+
+        match dir {
+            Direction::LEFT => combine(&self.rows),
+            Direction::RIGHT => {
+                let (rows, gained) = combine(&reverse_rows(&self.rows));
+                (reverse_rows(&rows), gained)
+            }
+            Direction::UP => {
+                let (rows, gained) = combine(&transpose(&self.rows));
+                (transpose(&rows), gained)
+            }
+            Direction::DOWN => {
+                let (rows, gained) = combine(&reverse_rows(&transpose(&self.rows)));
+                (transpose(&reverse_rows(&rows)), gained)
+            }
         }
-        Ok(new_grid)
     }
-    
+
     fn compress_row(&self, row: &Vec<u8>) -> Vec<u8> {
         let mut new_row = row.iter().filter(|&x| *x != 0).cloned().collect::<Vec<u8>>();
         new_row.append(&mut vec![0; row.len() - new_row.len()]);
         new_row
     }
 
-    fn combine_row(&self, row: &Vec<u8>) -> Vec<u8> {
+    /// Compresses and merges a single row, returning the new row and the
+    /// score gained: 2^(power+1) for every pair merged, i.e. the value of
+    /// the resulting tile.
+    fn combine_row(&self, row: &Vec<u8>) -> (Vec<u8>, u64) {
         let mut row = self.compress_row(&row);
+        let mut gained = 0u64;
         for i in 0..(row.len() - 1) {
             if row[i] == row[i+1] && row[i] != 0 {
+                let power = row[i];
                 row[i] += 1;
                 row[i+1] = 0;
+                gained += 1u64 << (power as u32 + 1);
             }
         }
-        self.compress_row(&row)
+        (self.compress_row(&row), gained)
     }
 
     fn add_random_number(&self) -> Result<Grid, &'static str> {
-        //get index of all 0 cells
-        let options: Vec<(usize, usize)> = self.rows.iter().enumerate().flat_map(|(x, row)| {
-            row.iter().enumerate().filter(|(_, &cell)| cell == 0).map(move |(y, _)| (x, y))
+        //get position of all 0 cells
+        let options: Vec<(Row, Col)> = self.each_row().flat_map(|row| {
+            self.each_col().filter(move |&col| self[(row, col)] == 0).map(move |col| (row, col))
         }).collect();
-        
+
         //check for no options (GAME OVER)
         if options.is_empty() {
             return Err("no more options");
@@ -221,22 +319,83 @@ impl Grid {
             power = 2;
         }
 
-        let mut new_rows = self.rows.clone();
-        new_rows[option.0][option.1] = power;
+        let mut new_grid = Grid { rows: self.rows.clone(), score: self.score, ..Default::default() };
+        new_grid[option] = power;
+
+        Ok(new_grid)
+    }
+
+    /// Iterates over the row coordinates of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert_eq!(grid.each_row().count(), 4);
+    /// ```
+    pub fn each_row(&self) -> impl Iterator<Item = Row> {
+        (0..self.rows.len()).map(Row)
+    }
+
+    /// Iterates over the column coordinates of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Grid;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert_eq!(grid.each_col().count(), 4);
+    /// ```
+    pub fn each_col(&self) -> impl Iterator<Item = Col> {
+        (0..self.rows[0].len()).map(Col)
+    }
+
+    /// Returns the in-bounds orthogonal neighbors of a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::{Grid, Row, Col};
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert_eq!(grid.neighbors((Row(0), Col(0))).len(), 2);
+    /// ```
+    pub fn neighbors(&self, pos: (Row, Col)) -> Vec<(Row, Col)> {
+        let (row, col) = pos;
+        let mut neighbors = Vec::with_capacity(4);
+
+        if row.0 > 0 {
+            neighbors.push((Row(row.0 - 1), col));
+        }
+        if row.0 + 1 < self.rows.len() {
+            neighbors.push((row + 1, col));
+        }
+        if col.0 > 0 {
+            neighbors.push((row, Col(col.0 - 1)));
+        }
+        if col.0 + 1 < self.rows[0].len() {
+            neighbors.push((row, col + 1));
+        }
 
-        Ok(Grid { rows: new_rows, ..Default::default() })
+        neighbors
     }
+
     fn formatted_numbers(&self) -> Vec<Vec<String>> {
 
         let mut longest_string_len = 2;
-        for number in self.rows.iter().flatten() {
-            if format_number(number, 0).len() > longest_string_len {
-                longest_string_len = format_number(number, 0).len();
+        for row in self.each_row() {
+            for col in self.each_col() {
+                if format_number(&self[(row, col)], 0).len() > longest_string_len {
+                    longest_string_len = format_number(&self[(row, col)], 0).len();
+                }
             }
         }
 
-        return self.rows.iter().map(|row| {
-            row.iter().map(|number| format_number(number, longest_string_len)).collect()
+        return self.each_row().map(|row| {
+            self.each_col().map(|col| format_number(&self[(row, col)], longest_string_len)).collect()
         }).collect();
 
         fn format_number(&number: &u8, len: usize) -> String {
@@ -256,6 +415,29 @@ impl Grid {
     }
 }
 
+impl Index<(Row, Col)> for Grid {
+    type Output = u8;
+    fn index(&self, (row, col): (Row, Col)) -> &u8 {
+        &self.rows[row.0][col.0]
+    }
+}
+
+impl IndexMut<(Row, Col)> for Grid {
+    fn index_mut(&mut self, (row, col): (Row, Col)) -> &mut u8 {
+        &mut self.rows[row.0][col.0]
+    }
+}
+
+/// Flips rows into columns and back.
+fn transpose(rows: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    (0..rows[0].len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect()
+}
+
+/// Reverses the order of cells within each row.
+fn reverse_rows(rows: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    rows.iter().map(|row| row.iter().rev().cloned().collect()).collect()
+}
+
 impl fmt::Debug for Grid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut grid_str = format!("{} by {} Grid:\n[\n", self.rows.len(), self.rows[0].len());
@@ -334,6 +516,77 @@ impl fmt::Display for Grid {
     }
 }
 
+/// Error returned when parsing a `Grid` from the text format written by
+/// `Grid::to_save_string` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    /// The input had no non-empty rows.
+    Empty,
+    /// A row had a different number of cells than the first row.
+    RaggedRow { row: usize, expected: usize, found: usize },
+    /// A cell was neither `.` nor a power of two of at least 2.
+    InvalidCell { row: usize, col: usize, value: String },
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridParseError::Empty => write!(f, "grid text had no rows"),
+            GridParseError::RaggedRow { row, expected, found } => {
+                write!(f, "row {} has {} cells, expected {}", row, found, expected)
+            }
+            GridParseError::InvalidCell { row, col, value } => {
+                write!(f, "invalid cell at row {}, col {}: {:?}", row, col, value)
+            }
+        }
+    }
+}
+
+impl Error for GridParseError {}
+
+impl FromStr for Grid {
+    type Err = GridParseError;
+
+    /// Parses a grid written one row per line, cells separated by
+    /// whitespace, `.` for empty and decimal tile values otherwise (e.g.
+    /// `2`, `4`, `8`), the inverse of `Grid::to_save_string`.
+    fn from_str(s: &str) -> Result<Grid, GridParseError> {
+        let lines: Vec<&str> = s.lines().filter(|line| !line.trim().is_empty()).collect();
+        if lines.is_empty() {
+            return Err(GridParseError::Empty);
+        }
+
+        let expected_len = lines[0].split_whitespace().count();
+        let mut rows = Vec::with_capacity(lines.len());
+
+        for (row, line) in lines.iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().collect();
+            if cells.len() != expected_len {
+                return Err(GridParseError::RaggedRow { row, expected: expected_len, found: cells.len() });
+            }
+
+            let mut new_row = Vec::with_capacity(cells.len());
+            for (col, &cell) in cells.iter().enumerate() {
+                let invalid = || GridParseError::InvalidCell { row, col, value: cell.to_string() };
+
+                let power = if cell == "." {
+                    0
+                } else {
+                    let value: u64 = cell.parse().map_err(|_| invalid())?;
+                    if value < 2 || !value.is_power_of_two() {
+                        return Err(invalid());
+                    }
+                    value.trailing_zeros() as u8
+                };
+                new_row.push(power);
+            }
+            rows.push(new_row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+}
+
     /// Used as parameters to the slide function.
     /// # Examples
     ///
@@ -345,8 +598,9 @@ impl fmt::Display for Grid {
     /// let grid = Grid::new(4, 4);
     /// let grid = grid.slide(Direction::Up);
     /// let grid = grid.slide(Direction::Down);
-    /// 
+    ///
     /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     LEFT,
     RIGHT,
@@ -354,6 +608,60 @@ pub enum Direction {
     DOWN,
 }
 
+impl Direction {
+    /// All four directions, in a fixed but unspecified order.
+    pub(crate) const ALL: [Direction; 4] = [Direction::LEFT, Direction::RIGHT, Direction::UP, Direction::DOWN];
+
+    /// Returns the direction facing the opposite way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Direction;
+    ///
+    /// assert_eq!(Direction::LEFT.opposite(), Direction::RIGHT);
+    /// ```
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::LEFT => Direction::RIGHT,
+            Direction::RIGHT => Direction::LEFT,
+            Direction::UP => Direction::DOWN,
+            Direction::DOWN => Direction::UP,
+        }
+    }
+
+    /// Returns the direction one quarter-turn clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Direction;
+    ///
+    /// assert_eq!(Direction::UP.rotate_cw(), Direction::RIGHT);
+    /// ```
+    pub fn rotate_cw(&self) -> Direction {
+        match self {
+            Direction::UP => Direction::RIGHT,
+            Direction::RIGHT => Direction::DOWN,
+            Direction::DOWN => Direction::LEFT,
+            Direction::LEFT => Direction::UP,
+        }
+    }
+
+    /// Returns the direction one quarter-turn counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_2048::Direction;
+    ///
+    /// assert_eq!(Direction::UP.rotate_ccw(), Direction::LEFT);
+    /// ```
+    pub fn rotate_ccw(&self) -> Direction {
+        self.rotate_cw().rotate_cw().rotate_cw()
+    }
+}
+
 type PipeMap = phf::Map<&'static str, &'static str>;
 
     /// Contains three pipe-map presets:
@@ -452,10 +760,12 @@ mod tests {
     #[test]
     fn combine_row() {
         let grid = Grid::new(4, 4);
-        let row = grid.combine_row(&vec![1, 0, 1, 0]);
+        let (row, gained) = grid.combine_row(&vec![1, 0, 1, 0]);
         assert_eq!(row, vec![2, 0, 0, 0]);
-        let row = grid.combine_row(&vec![2, 2, 3, 4, 6, 6, 5, 0, 6]);
+        assert_eq!(gained, 4);
+        let (row, gained) = grid.combine_row(&vec![2, 2, 3, 4, 6, 6, 5, 0, 6]);
         assert_eq!(row, vec![3, 3, 4, 7, 5, 6, 0, 0, 0]);
+        assert_eq!(gained, 8 + 128);
     }
 
     #[test]
@@ -466,4 +776,131 @@ mod tests {
         let grid = Grid::from_rows(new_rows.clone());
         assert_eq!(grid.rows, new_rows);
     }
+
+    #[test]
+    fn direction_rotation() {
+        assert_eq!(Direction::LEFT.opposite(), Direction::RIGHT);
+        assert_eq!(Direction::UP.opposite(), Direction::DOWN);
+        assert_eq!(Direction::UP.rotate_cw(), Direction::RIGHT);
+        assert_eq!(Direction::UP.rotate_ccw(), Direction::LEFT);
+        assert_eq!(Direction::UP.rotate_cw().rotate_ccw(), Direction::UP);
+    }
+
+    #[test]
+    fn tilt_directions_agree_on_a_square_grid() {
+        let grid = Grid::from_rows(vec![
+            vec![1, 1, 0, 2],
+            vec![0, 0, 0, 0],
+            vec![2, 0, 0, 2],
+            vec![0, 0, 0, 0],
+        ]);
+        assert_eq!(grid.tilt(Direction::LEFT).0, vec![
+            vec![2, 2, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![3, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        assert_eq!(grid.tilt(Direction::RIGHT).0, vec![
+            vec![0, 0, 2, 2],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 3],
+            vec![0, 0, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn indexing_by_row_and_col() {
+        let mut grid = Grid::new(4, 4);
+        grid[(Row(0), Col(0))] = 3;
+        assert_eq!(grid[(Row(0), Col(0))], 3);
+        assert_eq!(grid.each_row().count(), 4);
+        assert_eq!(grid.each_col().count(), 4);
+    }
+
+    #[test]
+    fn corner_has_two_neighbors() {
+        let grid = Grid::new(4, 4);
+        assert_eq!(grid.neighbors((Row(0), Col(0))).len(), 2);
+        assert_eq!(grid.neighbors((Row(1), Col(1))).len(), 4);
+    }
+
+    #[test]
+    fn save_string_round_trip() {
+        let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 2]]);
+        let text = grid.to_save_string();
+        assert_eq!(text, "2 .\n. 4");
+        let parsed: Grid = text.parse().unwrap();
+        assert_eq!(parsed.rows, grid.rows);
+    }
+
+    #[test]
+    fn rejects_ragged_and_invalid_input() {
+        assert_eq!("2 2\n4".parse::<Grid>(), Err(GridParseError::RaggedRow { row: 1, expected: 2, found: 1 }));
+        assert_eq!("2 3".parse::<Grid>(), Err(GridParseError::InvalidCell { row: 0, col: 1, value: "3".to_string() }));
+    }
+
+    #[test]
+    fn can_move_detects_merges_on_a_full_board() {
+        let full_but_mergeable = Grid::from_rows(vec![
+            vec![1, 1, 2, 3],
+            vec![2, 3, 4, 5],
+            vec![3, 4, 5, 6],
+            vec![4, 5, 6, 7],
+        ]);
+        assert!(full_but_mergeable.can_move());
+
+        let full_and_stuck = Grid::from_rows(vec![
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4, 5],
+            vec![3, 4, 5, 6],
+            vec![4, 5, 6, 7],
+        ]);
+        assert!(!full_and_stuck.can_move());
+    }
+
+    #[test]
+    fn slide_tracks_score_and_distinguishes_game_over() {
+        let grid = Grid::from_rows(vec![
+            vec![1, 1, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        assert_eq!(grid.score(), 0);
+        let slid = grid.slide(Direction::LEFT).unwrap();
+        assert_eq!(slid.score(), 4);
+
+        let stuck = Grid::from_rows(vec![
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4, 5],
+            vec![3, 4, 5, 6],
+            vec![4, 5, 6, 7],
+        ]);
+        assert_eq!(stuck.slide(Direction::LEFT), Err("no more options"));
+    }
+
+    #[test]
+    fn equality_and_hash_ignore_pipes_and_score() {
+        let a = Grid::from_rows(vec![
+            vec![1, 1, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        let b = Grid {
+            rows: a.rows.clone(),
+            pipes: PIPEMAPS.get("Thin").unwrap(),
+            score: 100,
+        };
+        assert_ne!(a.pipes as *const _, b.pipes as *const _);
+        assert_ne!(a.score, b.score);
+        assert_eq!(a, b);
+
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher_a = DefaultHasher::new();
+        a.hash(&mut hasher_a);
+        let mut hasher_b = DefaultHasher::new();
+        b.hash(&mut hasher_b);
+        assert_eq!(hasher_a.finish(), hasher_b.finish());
+    }
 }
\ No newline at end of file
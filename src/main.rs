@@ -1,7 +1,24 @@
 use cli_2048::{Grid, Direction};
-use crossterm::{execute, Result, event::{read,Event,KeyCode}, terminal};
+use crossterm::{execute, Result, event::{read,poll,Event,KeyCode}, terminal};
 use std::env;
+use std::fs;
 use std::io::{stdout};
+use std::time::Duration;
+
+/// Maximum expectimax layers the auto-player is allowed to search ahead.
+/// `Grid::best_move` now deepens iteratively and bails out once its own
+/// wall-clock budget elapses, so this only bounds search quality on a fast
+/// build - it no longer has to be tuned against measured per-move timings
+/// to keep the auto-play loop responsive to its "press any key to stop".
+const AI_DEPTH: u8 = 6;
+/// Where `q` saves the board. Loading reads whatever path is given as the
+/// only argument, so pass this path to resume a saved game.
+const SAVE_PATH: &str = "save.2048";
+
+fn print_grid(grid: &Grid) {
+    println!("Score: {}", grid.score());
+    println!("{grid}");
+}
 
 fn main() -> Result<()>{
     let args: Vec<String> = env::args().collect();
@@ -10,6 +27,17 @@ fn main() -> Result<()>{
 
     match args.len() {
         1 => {}
+        2 => {
+            //load a saved grid
+            let contents = fs::read_to_string(&args[1]).unwrap_or_else(|e| {
+                println!("Could not read save file: {}", e);
+                std::process::exit(1);
+            });
+            grid = contents.parse().unwrap_or_else(|e| {
+                println!("Could not parse save file: {}", e);
+                std::process::exit(1);
+            });
+        }
         3 => {
             //grid size
             grid = Grid::new(
@@ -32,7 +60,7 @@ fn main() -> Result<()>{
         terminal::SetTitle("2048"),
     ).unwrap();
     
-    println!("{grid}");
+    print_grid(&grid);
     loop {
         match read()? {
             Event::Key(event) => {
@@ -41,9 +69,39 @@ fn main() -> Result<()>{
                 //println!("{}", input);
 
                 if input == KeyCode::Char('q') {
+                    if let Err(e) = fs::write(SAVE_PATH, grid.to_save_string()) {
+                        println!("Could not save game: {}", e);
+                    }
                     println!("Quitting...");
                     std::process::exit(0);
                 }
+                if input == KeyCode::Char('p') {
+                    println!("Auto-play: press any key to stop.");
+                    loop {
+                        let direction = match grid.best_move(AI_DEPTH) {
+                            Some(direction) => direction,
+                            None => {
+                                println!("Game over! No more options!");
+                                std::process::exit(0);
+                            }
+                        };
+                        grid = match grid.slide(direction) {
+                            Ok(grid) => grid,
+                            Err(_) => {
+                                println!("Game over! No more options!");
+                                std::process::exit(0);
+                            }
+                        };
+                        print_grid(&grid);
+
+                        std::thread::sleep(Duration::from_millis(200));
+                        if poll(Duration::from_millis(0))? {
+                            read()?;
+                            break;
+                        }
+                    }
+                    continue;
+                }
                 let direction = match input {
                     KeyCode::Char('a') | KeyCode::Left => Direction::LEFT,
                     KeyCode::Char('d') | KeyCode::Right => Direction::RIGHT,
@@ -51,7 +109,7 @@ fn main() -> Result<()>{
                     KeyCode::Char('s') | KeyCode::Down => Direction::DOWN,
                     _ => {
                         println!("Invalid input!");
-                        println!("{grid}");
+                        print_grid(&grid);
                         continue;
                     }
                 };
@@ -63,14 +121,18 @@ fn main() -> Result<()>{
                                 println!("Game over! No more options!");
                                 std::process::exit(0);
                             }
+                            "cannot move in that direction" => {
+                                print_grid(&grid);
+                                continue;
+                            }
                             _ => {
                                 panic!("{}", e);
                             }
                         }
                     }
                 };
-                println!("{grid}");
-                
+                print_grid(&grid);
+
             },
             _ => {},
         }